@@ -13,26 +13,34 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::iter::{Sum, Product};
 use std::ops::{Add, Sub, Mul, Div, Rem, AddAssign, SubAssign, MulAssign, DivAssign, RemAssign, Neg};
 use std::num::FpCategory;
-use num_traits::{Float, Num};
-use num_traits::cast::{NumCast, ToPrimitive};
+use num_traits::Num;
+use num_traits::float::FloatCore;
+use num_traits::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem};
+use num_traits::{Euclid, CheckedEuclid};
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::cast::{NumCast, ToPrimitive, FromPrimitive};
 use num_traits::identities::{Zero, One};
+use num_traits::{Signed, Bounded};
 use ::{FloatChecker, NoisyFloat};
 
-impl<F: Float, C: FloatChecker<F>> Clone for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> Clone for NoisyFloat<F, C> {
     #[inline] fn clone(&self) -> Self { Self::unchecked_new(self.value) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Copy for NoisyFloat<F, C> {}
+impl<F: FloatCore, C: FloatChecker<F>> Copy for NoisyFloat<F, C> {}
 
-impl<F: Float, C: FloatChecker<F>> PartialEq for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> PartialEq for NoisyFloat<F, C> {
     #[inline] fn eq(&self, other: &Self) -> bool { self.value.eq(&other.value) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Eq for NoisyFloat<F, C> {}
+impl<F: FloatCore, C: FloatChecker<F>> Eq for NoisyFloat<F, C> {}
 
-impl<F: Float, C: FloatChecker<F>> PartialOrd for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> PartialOrd for NoisyFloat<F, C> {
     #[inline] fn partial_cmp(&self, other: &Self) -> Option<Ordering> { self.value.partial_cmp(&other.value) }
     #[inline] fn lt(&self, other: &Self) -> bool { self.value.lt(&other.value) }
     #[inline] fn le(&self, other: &Self) -> bool { self.value.le(&other.value) }
@@ -40,7 +48,7 @@ impl<F: Float, C: FloatChecker<F>> PartialOrd for NoisyFloat<F, C> {
     #[inline] fn ge(&self, other: &Self) -> bool { self.value.ge(&other.value) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Ord for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> Ord for NoisyFloat<F, C> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         if self.value < other.value {
@@ -53,71 +61,192 @@ impl<F: Float, C: FloatChecker<F>> Ord for NoisyFloat<F, C> {
     }
 }
 
-impl<F: Float, C: FloatChecker<F>> Add for NoisyFloat<F, C> {
+/// Maps a float type to its raw IEEE-754 bit representation, used to give
+/// `NoisyFloat` a `Hash` impl consistent with its `PartialEq`.
+///
+/// `num_traits::Float`/`FloatCore` don't expose `to_bits`, so this crate
+/// defines its own narrow trait for `f32` and `f64`.
+pub trait ToBits {
+    type Bits: Hash;
+    fn to_bits(self) -> Self::Bits;
+}
+
+impl ToBits for f32 {
+    type Bits = u32;
+    #[inline] fn to_bits(self) -> u32 { f32::to_bits(self) }
+}
+
+impl ToBits for f64 {
+    type Bits = u64;
+    #[inline] fn to_bits(self) -> u64 { f64::to_bits(self) }
+}
+
+impl<F: FloatCore + ToBits, C: FloatChecker<F>> Hash for NoisyFloat<F, C> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // +0.0 and -0.0 compare equal but have different bit patterns, so
+        // both must hash to the same value to respect the `Eq`/`Hash` contract.
+        if self.value.is_zero() {
+            F::zero().to_bits().hash(state);
+        } else {
+            self.value.to_bits().hash(state);
+        }
+    }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> Add for NoisyFloat<F, C> {
     type Output = Self;
     #[inline] fn add(self, rhs: Self) -> Self { Self::new(self.value.add(rhs.value)) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Sub for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> Sub for NoisyFloat<F, C> {
     type Output = Self;
     #[inline] fn sub(self, rhs: Self) -> Self { Self::new(self.value.sub(rhs.value)) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Mul for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> Mul for NoisyFloat<F, C> {
     type Output = Self;
     #[inline] fn mul(self, rhs: Self) -> Self { Self::new(self.value.mul(rhs.value)) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Div for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> Div for NoisyFloat<F, C> {
     type Output = Self;
     #[inline] fn div(self, rhs: Self) -> Self { Self::new(self.value.div(rhs.value)) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Rem for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> Rem for NoisyFloat<F, C> {
     type Output = Self;
     #[inline] fn rem(self, rhs: Self) -> Self { Self::new(self.value.rem(rhs.value)) }
 }
 
-impl<F: Float + AddAssign, C: FloatChecker<F>> AddAssign for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> CheckedAdd for NoisyFloat<F, C> {
+    #[inline] fn checked_add(&self, rhs: &Self) -> Option<Self> { Self::try_new(self.value.add(rhs.value)) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> CheckedSub for NoisyFloat<F, C> {
+    #[inline] fn checked_sub(&self, rhs: &Self) -> Option<Self> { Self::try_new(self.value.sub(rhs.value)) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> CheckedMul for NoisyFloat<F, C> {
+    #[inline] fn checked_mul(&self, rhs: &Self) -> Option<Self> { Self::try_new(self.value.mul(rhs.value)) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> CheckedDiv for NoisyFloat<F, C> {
+    #[inline] fn checked_div(&self, rhs: &Self) -> Option<Self> { Self::try_new(self.value.div(rhs.value)) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> CheckedRem for NoisyFloat<F, C> {
+    #[inline] fn checked_rem(&self, rhs: &Self) -> Option<Self> { Self::try_new(self.value.rem(rhs.value)) }
+}
+
+/// The raw `div_euclid`, operating on `F` directly so it can be reused by
+/// both the panicking and checked `Euclid` impls.
+#[inline]
+fn raw_div_euclid<F: FloatCore>(a: F, b: F) -> F {
+    let q = (a / b).trunc();
+    if a % b < F::zero() {
+        if b > F::zero() { q - F::one() } else { q + F::one() }
+    } else {
+        q
+    }
+}
+
+/// The raw `rem_euclid`, operating on `F` directly so it can be reused by
+/// both the panicking and checked `Euclid` impls.
+#[inline]
+fn raw_rem_euclid<F: FloatCore>(a: F, b: F) -> F {
+    let r = a % b;
+    if r < F::zero() { r + b.abs() } else { r }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> Euclid for NoisyFloat<F, C> {
+    #[inline] fn div_euclid(&self, rhs: &Self) -> Self { Self::new(raw_div_euclid(self.value, rhs.value)) }
+    #[inline] fn rem_euclid(&self, rhs: &Self) -> Self { Self::new(raw_rem_euclid(self.value, rhs.value)) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> CheckedEuclid for NoisyFloat<F, C> {
+    #[inline]
+    fn checked_div_euclid(&self, rhs: &Self) -> Option<Self> {
+        if rhs.value.is_zero() { return None; }
+        Self::try_new(raw_div_euclid(self.value, rhs.value))
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, rhs: &Self) -> Option<Self> {
+        if rhs.value.is_zero() { return None; }
+        Self::try_new(raw_rem_euclid(self.value, rhs.value))
+    }
+}
+
+impl<F: FloatCore + AddAssign, C: FloatChecker<F>> AddAssign for NoisyFloat<F, C> {
     #[inline] fn add_assign(&mut self, rhs: Self) { self.value.add_assign(rhs.value); C::assert(self.value); }
 }
 
-impl<F: Float + SubAssign, C: FloatChecker<F>> SubAssign for NoisyFloat<F, C> {
+impl<F: FloatCore + SubAssign, C: FloatChecker<F>> SubAssign for NoisyFloat<F, C> {
     #[inline] fn sub_assign(&mut self, rhs: Self) { self.value.sub_assign(rhs.value); C::assert(self.value); }
 }
 
-impl<F: Float + MulAssign, C: FloatChecker<F>> MulAssign for NoisyFloat<F, C> {
+impl<F: FloatCore + MulAssign, C: FloatChecker<F>> MulAssign for NoisyFloat<F, C> {
     #[inline] fn mul_assign(&mut self, rhs: Self) { self.value.mul_assign(rhs.value); C::assert(self.value); }
 }
 
-impl<F: Float + DivAssign, C: FloatChecker<F>> DivAssign for NoisyFloat<F, C> {
+impl<F: FloatCore + DivAssign, C: FloatChecker<F>> DivAssign for NoisyFloat<F, C> {
     #[inline] fn div_assign(&mut self, rhs: Self) { self.value.div_assign(rhs.value); C::assert(self.value); }
 }
 
-impl<F: Float + RemAssign, C: FloatChecker<F>> RemAssign for NoisyFloat<F, C> {
+impl<F: FloatCore + RemAssign, C: FloatChecker<F>> RemAssign for NoisyFloat<F, C> {
     #[inline] fn rem_assign(&mut self, rhs: Self) { self.value.rem_assign(rhs.value); C::assert(self.value); }
 }
 
-impl<F: Float, C: FloatChecker<F>> Neg for NoisyFloat<F, C> {
+impl<F: FloatCore + Neg<Output = F>, C: FloatChecker<F>> Neg for NoisyFloat<F, C> {
     type Output = Self;
     #[inline] fn neg(self) -> Self { Self::new(self.value.neg()) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Zero for NoisyFloat<F, C> {
+impl<F: FloatCore + Neg<Output = F>, C: FloatChecker<F>> Signed for NoisyFloat<F, C> {
+    #[inline] fn abs(&self) -> Self { Self::new(self.value.abs()) }
+    #[inline] fn abs_sub(&self, other: &Self) -> Self { if *self <= *other { Self::zero() } else { *self - *other } }
+    #[inline] fn signum(&self) -> Self { Self::new(self.value.signum()) }
+    #[inline] fn is_positive(&self) -> bool { self.value.is_sign_positive() }
+    #[inline] fn is_negative(&self) -> bool { self.value.is_sign_negative() }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> Bounded for NoisyFloat<F, C> {
+    #[inline] fn min_value() -> Self { Self::new(F::min_value()) }
+    #[inline] fn max_value() -> Self { Self::new(F::max_value()) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> Zero for NoisyFloat<F, C> {
     #[inline] fn zero() -> Self { Self::unchecked_new(F::zero()) }
     #[inline] fn is_zero(&self) -> bool { self.value.is_zero() }
 }
 
-impl<F: Float, C: FloatChecker<F>> One for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> One for NoisyFloat<F, C> {
     #[inline] fn one() -> Self { Self::unchecked_new(F::one()) }
 }
 
-impl<F: Float, C: FloatChecker<F>> Num for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> Sum for NoisyFloat<F, C> {
+    #[inline] fn sum<I: Iterator<Item = Self>>(iter: I) -> Self { iter.fold(Self::zero(), Add::add) }
+}
+
+impl<'a, F: FloatCore, C: FloatChecker<F>> Sum<&'a Self> for NoisyFloat<F, C> {
+    #[inline] fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self { iter.fold(Self::zero(), |a, &b| a.add(b)) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> Product for NoisyFloat<F, C> {
+    #[inline] fn product<I: Iterator<Item = Self>>(iter: I) -> Self { iter.fold(Self::one(), Mul::mul) }
+}
+
+impl<'a, F: FloatCore, C: FloatChecker<F>> Product<&'a Self> for NoisyFloat<F, C> {
+    #[inline] fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self { iter.fold(Self::one(), |a, &b| a.mul(b)) }
+}
+
+impl<F: FloatCore, C: FloatChecker<F>> Num for NoisyFloat<F, C> {
     type FromStrRadixErr = F::FromStrRadixErr;
     #[inline] fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> { F::from_str_radix(str, radix).map(|v| Self::new(v)) }
 }
 
-impl<F: Float, C: FloatChecker<F>> ToPrimitive for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> ToPrimitive for NoisyFloat<F, C> {
     #[inline] fn to_i64(&self) -> Option<i64> { self.value.to_i64() }
     #[inline] fn to_u64(&self) -> Option<u64> { self.value.to_u64() }
     #[inline] fn to_isize(&self) -> Option<isize> { self.value.to_isize() }
@@ -132,7 +261,7 @@ impl<F: Float, C: FloatChecker<F>> ToPrimitive for NoisyFloat<F, C> {
     #[inline] fn to_f64(&self) -> Option<f64> { self.value.to_f64() }
 }
 
-impl<F: Float, C: FloatChecker<F>> NumCast for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> NumCast for NoisyFloat<F, C> {
     #[inline] fn from<T: ToPrimitive>(n: T) -> Option<Self> {
         match F::from(n) {
             Some(value) => Self::try_new(value),
@@ -141,13 +270,32 @@ impl<F: Float, C: FloatChecker<F>> NumCast for NoisyFloat<F, C> {
     }
 }
 
-impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> FromPrimitive for NoisyFloat<F, C> {
+    #[inline] fn from_i64(n: i64) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_u64(n: u64) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_isize(n: isize) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_i8(n: i8) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_i16(n: i16) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_i32(n: i32) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_usize(n: usize) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_u8(n: u8) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_u16(n: u16) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_u32(n: u32) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_f32(n: f32) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+    #[inline] fn from_f64(n: f64) -> Option<Self> { F::from(n).and_then(Self::try_new) }
+}
+
+/// Implementation of the basic, non-transcendental methods of `FloatCore`,
+/// used in place of `Float` when the `std` feature is disabled.
+#[cfg(not(feature = "std"))]
+impl<F: FloatCore, C: FloatChecker<F>> FloatCore for NoisyFloat<F, C> {
     #[inline] fn nan() -> Self { panic!("unexpected NaN") }
     #[inline] fn infinity() -> Self { Self::new(F::infinity()) }
     #[inline] fn neg_infinity() -> Self { Self::new(F::neg_infinity()) }
     #[inline] fn neg_zero() -> Self { Self::new(F::neg_zero()) }
     #[inline] fn min_value() -> Self { Self::new(F::min_value()) }
     #[inline] fn min_positive_value() -> Self { Self::new(F::min_positive_value()) }
+    #[inline] fn epsilon() -> Self { Self::new(F::epsilon()) }
     #[inline] fn max_value() -> Self { Self::new(F::max_value()) }
     #[inline] fn is_nan(self) -> bool { false }
     #[inline] fn is_infinite(self) -> bool { self.value.is_infinite() }
@@ -163,9 +311,169 @@ impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
     #[inline] fn signum(self) -> Self { Self::new(self.value.signum()) }
     #[inline] fn is_sign_positive(self) -> bool { self.value.is_sign_positive() }
     #[inline] fn is_sign_negative(self) -> bool { self.value.is_sign_negative() }
-    #[inline] fn mul_add(self, a: Self, b: Self) -> Self { Self::new(self.value.mul_add(a.value, b.value)) }
+    #[inline] fn min(self, other: Self) -> Self { Self::new(self.value.min(other.value)) }
+    #[inline] fn max(self, other: Self) -> Self { Self::new(self.value.max(other.value)) }
     #[inline] fn recip(self) -> Self { Self::new(self.value.recip()) }
     #[inline] fn powi(self, n: i32) -> Self { Self::new(self.value.powi(n)) }
+    #[inline] fn to_degrees(self) -> Self { Self::new(self.value.to_degrees()) }
+    #[inline] fn to_radians(self) -> Self { Self::new(self.value.to_radians()) }
+    #[inline] fn integer_decode(self) -> (u64, i16, i8) { self.value.integer_decode() }
+}
+
+/// Maps a concrete float type to the `libm`-backed transcendental functions
+/// that `FloatCore` does not provide, so `NoisyFloat` can still offer them
+/// without `std`.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+pub trait LibmFloat: FloatCore {
+    fn libm_sqrt(self) -> Self;
+    fn libm_cbrt(self) -> Self;
+    fn libm_exp(self) -> Self;
+    fn libm_exp2(self) -> Self;
+    fn libm_exp_m1(self) -> Self;
+    fn libm_ln(self) -> Self;
+    fn libm_ln_1p(self) -> Self;
+    fn libm_log2(self) -> Self;
+    fn libm_log10(self) -> Self;
+    fn libm_log(self, base: Self) -> Self;
+    fn libm_powf(self, n: Self) -> Self;
+    fn libm_hypot(self, other: Self) -> Self;
+    fn libm_sin(self) -> Self;
+    fn libm_cos(self) -> Self;
+    fn libm_tan(self) -> Self;
+    fn libm_asin(self) -> Self;
+    fn libm_acos(self) -> Self;
+    fn libm_atan(self) -> Self;
+    fn libm_atan2(self, other: Self) -> Self;
+    fn libm_sinh(self) -> Self;
+    fn libm_cosh(self) -> Self;
+    fn libm_tanh(self) -> Self;
+    fn libm_asinh(self) -> Self;
+    fn libm_acosh(self) -> Self;
+    fn libm_atanh(self) -> Self;
+    fn libm_mul_add(self, a: Self, b: Self) -> Self;
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl LibmFloat for f32 {
+    #[inline] fn libm_sqrt(self) -> Self { libm::sqrtf(self) }
+    #[inline] fn libm_cbrt(self) -> Self { libm::cbrtf(self) }
+    #[inline] fn libm_exp(self) -> Self { libm::expf(self) }
+    #[inline] fn libm_exp2(self) -> Self { libm::exp2f(self) }
+    #[inline] fn libm_exp_m1(self) -> Self { libm::expm1f(self) }
+    #[inline] fn libm_ln(self) -> Self { libm::logf(self) }
+    #[inline] fn libm_ln_1p(self) -> Self { libm::log1pf(self) }
+    #[inline] fn libm_log2(self) -> Self { libm::log2f(self) }
+    #[inline] fn libm_log10(self) -> Self { libm::log10f(self) }
+    #[inline] fn libm_log(self, base: Self) -> Self { self.libm_ln() / base.libm_ln() }
+    #[inline] fn libm_powf(self, n: Self) -> Self { libm::powf(self, n) }
+    #[inline] fn libm_hypot(self, other: Self) -> Self { libm::hypotf(self, other) }
+    #[inline] fn libm_sin(self) -> Self { libm::sinf(self) }
+    #[inline] fn libm_cos(self) -> Self { libm::cosf(self) }
+    #[inline] fn libm_tan(self) -> Self { libm::tanf(self) }
+    #[inline] fn libm_asin(self) -> Self { libm::asinf(self) }
+    #[inline] fn libm_acos(self) -> Self { libm::acosf(self) }
+    #[inline] fn libm_atan(self) -> Self { libm::atanf(self) }
+    #[inline] fn libm_atan2(self, other: Self) -> Self { libm::atan2f(self, other) }
+    #[inline] fn libm_sinh(self) -> Self { libm::sinhf(self) }
+    #[inline] fn libm_cosh(self) -> Self { libm::coshf(self) }
+    #[inline] fn libm_tanh(self) -> Self { libm::tanhf(self) }
+    #[inline] fn libm_asinh(self) -> Self { libm::asinhf(self) }
+    #[inline] fn libm_acosh(self) -> Self { libm::acoshf(self) }
+    #[inline] fn libm_atanh(self) -> Self { libm::atanhf(self) }
+    #[inline] fn libm_mul_add(self, a: Self, b: Self) -> Self { libm::fmaf(self, a, b) }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl LibmFloat for f64 {
+    #[inline] fn libm_sqrt(self) -> Self { libm::sqrt(self) }
+    #[inline] fn libm_cbrt(self) -> Self { libm::cbrt(self) }
+    #[inline] fn libm_exp(self) -> Self { libm::exp(self) }
+    #[inline] fn libm_exp2(self) -> Self { libm::exp2(self) }
+    #[inline] fn libm_exp_m1(self) -> Self { libm::expm1(self) }
+    #[inline] fn libm_ln(self) -> Self { libm::log(self) }
+    #[inline] fn libm_ln_1p(self) -> Self { libm::log1p(self) }
+    #[inline] fn libm_log2(self) -> Self { libm::log2(self) }
+    #[inline] fn libm_log10(self) -> Self { libm::log10(self) }
+    #[inline] fn libm_log(self, base: Self) -> Self { self.libm_ln() / base.libm_ln() }
+    #[inline] fn libm_powf(self, n: Self) -> Self { libm::pow(self, n) }
+    #[inline] fn libm_hypot(self, other: Self) -> Self { libm::hypot(self, other) }
+    #[inline] fn libm_sin(self) -> Self { libm::sin(self) }
+    #[inline] fn libm_cos(self) -> Self { libm::cos(self) }
+    #[inline] fn libm_tan(self) -> Self { libm::tan(self) }
+    #[inline] fn libm_asin(self) -> Self { libm::asin(self) }
+    #[inline] fn libm_acos(self) -> Self { libm::acos(self) }
+    #[inline] fn libm_atan(self) -> Self { libm::atan(self) }
+    #[inline] fn libm_atan2(self, other: Self) -> Self { libm::atan2(self, other) }
+    #[inline] fn libm_sinh(self) -> Self { libm::sinh(self) }
+    #[inline] fn libm_cosh(self) -> Self { libm::cosh(self) }
+    #[inline] fn libm_tanh(self) -> Self { libm::tanh(self) }
+    #[inline] fn libm_asinh(self) -> Self { libm::asinh(self) }
+    #[inline] fn libm_acosh(self) -> Self { libm::acosh(self) }
+    #[inline] fn libm_atanh(self) -> Self { libm::atanh(self) }
+    #[inline] fn libm_mul_add(self, a: Self, b: Self) -> Self { libm::fma(self, a, b) }
+}
+
+/// Transcendental methods for `NoisyFloat`, available without `std` via the
+/// `libm` feature. These mirror the subset of `num_traits::Float` that
+/// `FloatCore` omits.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl<F: LibmFloat, C: FloatChecker<F>> NoisyFloat<F, C> {
+    #[inline] pub fn sqrt(self) -> Self { Self::new(self.value.libm_sqrt()) }
+    #[inline] pub fn cbrt(self) -> Self { Self::new(self.value.libm_cbrt()) }
+    #[inline] pub fn exp(self) -> Self { Self::new(self.value.libm_exp()) }
+    #[inline] pub fn exp2(self) -> Self { Self::new(self.value.libm_exp2()) }
+    #[inline] pub fn exp_m1(self) -> Self { Self::new(self.value.libm_exp_m1()) }
+    #[inline] pub fn ln(self) -> Self { Self::new(self.value.libm_ln()) }
+    #[inline] pub fn ln_1p(self) -> Self { Self::new(self.value.libm_ln_1p()) }
+    #[inline] pub fn log2(self) -> Self { Self::new(self.value.libm_log2()) }
+    #[inline] pub fn log10(self) -> Self { Self::new(self.value.libm_log10()) }
+    #[inline] pub fn log(self, base: Self) -> Self { Self::new(self.value.libm_log(base.value)) }
+    #[inline] pub fn powf(self, n: Self) -> Self { Self::new(self.value.libm_powf(n.value)) }
+    #[inline] pub fn hypot(self, other: Self) -> Self { Self::new(self.value.libm_hypot(other.value)) }
+    #[inline] pub fn sin(self) -> Self { Self::new(self.value.libm_sin()) }
+    #[inline] pub fn cos(self) -> Self { Self::new(self.value.libm_cos()) }
+    #[inline] pub fn tan(self) -> Self { Self::new(self.value.libm_tan()) }
+    #[inline] pub fn asin(self) -> Self { Self::new(self.value.libm_asin()) }
+    #[inline] pub fn acos(self) -> Self { Self::new(self.value.libm_acos()) }
+    #[inline] pub fn atan(self) -> Self { Self::new(self.value.libm_atan()) }
+    #[inline] pub fn atan2(self, other: Self) -> Self { Self::new(self.value.libm_atan2(other.value)) }
+    #[inline] pub fn sinh(self) -> Self { Self::new(self.value.libm_sinh()) }
+    #[inline] pub fn cosh(self) -> Self { Self::new(self.value.libm_cosh()) }
+    #[inline] pub fn tanh(self) -> Self { Self::new(self.value.libm_tanh()) }
+    #[inline] pub fn asinh(self) -> Self { Self::new(self.value.libm_asinh()) }
+    #[inline] pub fn acosh(self) -> Self { Self::new(self.value.libm_acosh()) }
+    #[inline] pub fn atanh(self) -> Self { Self::new(self.value.libm_atanh()) }
+    #[inline] pub fn mul_add(self, a: Self, b: Self) -> Self { Self::new(self.value.libm_mul_add(a.value, b.value)) }
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + FloatCore, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
+    // `F` is bound by both `Float` and `FloatCore` here, so every method they
+    // share must be fully qualified to avoid an ambiguous-method-resolution error.
+    #[inline] fn nan() -> Self { panic!("unexpected NaN") }
+    #[inline] fn infinity() -> Self { Self::new(<F as Float>::infinity()) }
+    #[inline] fn neg_infinity() -> Self { Self::new(<F as Float>::neg_infinity()) }
+    #[inline] fn neg_zero() -> Self { Self::new(<F as Float>::neg_zero()) }
+    #[inline] fn min_value() -> Self { Self::new(<F as Float>::min_value()) }
+    #[inline] fn min_positive_value() -> Self { Self::new(<F as Float>::min_positive_value()) }
+    #[inline] fn max_value() -> Self { Self::new(<F as Float>::max_value()) }
+    #[inline] fn is_nan(self) -> bool { false }
+    #[inline] fn is_infinite(self) -> bool { Float::is_infinite(self.value) }
+    #[inline] fn is_finite(self) -> bool { Float::is_finite(self.value) }
+    #[inline] fn is_normal(self) -> bool { Float::is_normal(self.value) }
+    #[inline] fn classify(self) -> FpCategory { Float::classify(self.value) }
+    #[inline] fn floor(self) -> Self { Self::new(Float::floor(self.value)) }
+    #[inline] fn ceil(self) -> Self { Self::new(Float::ceil(self.value)) }
+    #[inline] fn round(self) -> Self { Self::new(Float::round(self.value)) }
+    #[inline] fn trunc(self) -> Self { Self::new(Float::trunc(self.value)) }
+    #[inline] fn fract(self) -> Self { Self::new(Float::fract(self.value)) }
+    #[inline] fn abs(self) -> Self { Self::new(Float::abs(self.value)) }
+    #[inline] fn signum(self) -> Self { Self::new(Float::signum(self.value)) }
+    #[inline] fn is_sign_positive(self) -> bool { Float::is_sign_positive(self.value) }
+    #[inline] fn is_sign_negative(self) -> bool { Float::is_sign_negative(self.value) }
+    #[inline] fn mul_add(self, a: Self, b: Self) -> Self { Self::new(self.value.mul_add(a.value, b.value)) }
+    #[inline] fn recip(self) -> Self { Self::new(Float::recip(self.value)) }
+    #[inline] fn powi(self, n: i32) -> Self { Self::new(Float::powi(self.value, n)) }
     #[inline] fn powf(self, n: Self) -> Self { Self::new(self.value.powf(n.value)) }
     #[inline] fn sqrt(self) -> Self { Self::new(self.value.sqrt()) }
     #[inline] fn exp(self) -> Self { Self::new(self.value.exp()) }
@@ -174,8 +482,8 @@ impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
     #[inline] fn log(self, base: Self) -> Self { Self::new(self.value.log(base.value)) }
     #[inline] fn log2(self) -> Self {Self::new(self.value.log2()) }
     #[inline] fn log10(self) -> Self { Self::new(self.value.log10()) }
-    #[inline] fn max(self, other: Self) -> Self { Self::new(self.value.max(other.value)) }
-    #[inline] fn min(self, other: Self) -> Self { Self::new(self.value.min(other.value)) }
+    #[inline] fn max(self, other: Self) -> Self { Self::new(Float::max(self.value, other.value)) }
+    #[inline] fn min(self, other: Self) -> Self { Self::new(Float::min(self.value, other.value)) }
     #[inline] fn abs_sub(self, other: Self) -> Self { Self::new(self.value.abs_sub(other.value)) }
     #[inline] fn cbrt(self) -> Self { Self::new(self.value.cbrt()) }
     #[inline] fn hypot(self, other: Self) -> Self { Self::new(self.value.hypot(other.value)) }
@@ -195,7 +503,7 @@ impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
     #[inline] fn asinh(self) -> Self { Self::new(self.value.asinh()) }
     #[inline] fn acosh(self) -> Self { Self::new(self.value.acosh()) }
     #[inline] fn atanh(self) -> Self { Self::new(self.value.atanh()) }
-    #[inline] fn integer_decode(self) -> (u64, i16, i8) { self.value.integer_decode() }
-    #[inline] fn to_degrees(self) -> Self { Self::new(self.value.to_degrees()) }
-    #[inline] fn to_radians(self) -> Self { Self::new(self.value.to_radians()) }
+    #[inline] fn integer_decode(self) -> (u64, i16, i8) { Float::integer_decode(self.value) }
+    #[inline] fn to_degrees(self) -> Self { Self::new(Float::to_degrees(self.value)) }
+    #[inline] fn to_radians(self) -> Self { Self::new(Float::to_radians(self.value)) }
 }
\ No newline at end of file
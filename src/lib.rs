@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 //! This crate contains floating point types that panic if they are set
 //! to an illegal value, such as NaN.
 //!
@@ -64,16 +66,33 @@
 //! assert!(values.iter().cloned().min() == Some(n32(-1.5)));
 //! assert!(values.iter().cloned().max() == Some(N32::infinity()));
 //! ```
+//!
+//! # `no_std`
+//! This crate can be used without `std` by disabling the default `std` feature.
+//! Without `std`, `NoisyFloat` is built on top of `num_traits::float::FloatCore`
+//! rather than `num_traits::Float`, so the transcendental methods (`sqrt`, `sin`,
+//! `exp`, `powf`, etc.) are unavailable unless the `libm` feature is also enabled,
+//! in which case they are routed through the `libm` crate instead.
 
 extern crate num_traits;
 extern crate approx;
+#[cfg(not(feature = "std"))]
+extern crate core as std;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+extern crate libm;
 #[cfg(feature = "algebra")]
 extern crate alga;
 #[cfg(feature = "algebra")]
 #[macro_use]
 extern crate alga_derive;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
 
 mod float_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 pub mod checkers;
 pub mod types;
 
@@ -86,13 +105,16 @@ pub mod types;
 pub mod prelude {
     pub use types::*;
 
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[doc(no_inline)]
     pub use num_traits::Float;
+    #[doc(no_inline)]
+    pub use num_traits::float::FloatCore;
 }
 
 use std::marker::PhantomData;
 use std::fmt;
-use num_traits::Float;
+use num_traits::float::FloatCore;
 
 #[cfg(feature = "algebra")]
 use alga::general::{Additive, Multiplicative};
@@ -131,12 +153,12 @@ pub trait FloatChecker<F> {
 #[repr(C)]
 #[cfg_attr(feature = "algebra", derive(Alga))]
 #[cfg_attr(feature = "algebra", alga_traits(Field(Additive, Multiplicative)))]
-pub struct NoisyFloat<F: Float, C: FloatChecker<F>> {
+pub struct NoisyFloat<F: FloatCore, C: FloatChecker<F>> {
     value: F,
     checker: PhantomData<C>
 }
 
-impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
+impl<F: FloatCore, C: FloatChecker<F>> NoisyFloat<F, C> {
     /// Constructs a `NoisyFloat` with the given value.
     ///
     /// Uses the `FloatChecker` to assert that the value is valid.
@@ -196,35 +218,35 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
     }
 }
 
-impl<F: Float + Default, C: FloatChecker<F>> Default for NoisyFloat<F, C> {
+impl<F: FloatCore + Default, C: FloatChecker<F>> Default for NoisyFloat<F, C> {
     #[inline]
     fn default() -> Self {
         Self::new(F::default())
     }
 }
 
-impl<F: Float + fmt::Debug, C: FloatChecker<F>> fmt::Debug for NoisyFloat<F, C> {
+impl<F: FloatCore + fmt::Debug, C: FloatChecker<F>> fmt::Debug for NoisyFloat<F, C> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         fmt::Debug::fmt(&self.value, f)
     }
 }
 
-impl<F: Float + fmt::Display, C: FloatChecker<F>> fmt::Display for NoisyFloat<F, C> {
+impl<F: FloatCore + fmt::Display, C: FloatChecker<F>> fmt::Display for NoisyFloat<F, C> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         fmt::Display::fmt(&self.value, f)
     }
 }
 
-impl<F: Float + fmt::LowerExp, C: FloatChecker<F>> fmt::LowerExp for NoisyFloat<F, C> {
+impl<F: FloatCore + fmt::LowerExp, C: FloatChecker<F>> fmt::LowerExp for NoisyFloat<F, C> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         fmt::LowerExp::fmt(&self.value, f)
     }
 }
 
-impl<F: Float + fmt::UpperExp, C: FloatChecker<F>> fmt::UpperExp for NoisyFloat<F, C> {
+impl<F: FloatCore + fmt::UpperExp, C: FloatChecker<F>> fmt::UpperExp for NoisyFloat<F, C> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         fmt::UpperExp::fmt(&self.value, f)
@@ -247,6 +269,7 @@ mod tests {
         let mut value = n64(18.0);
         value %= n64(5.0);
         assert_eq!(-value, n64(-3.0));
+        #[cfg(feature = "std")]
         assert_eq!(r64(1.0).exp(), consts::E);
         assert_eq!((N64::try_new(1.0).unwrap() / N64::infinity()), 0.0);
         assert_eq!(N64::from_f32(f32::INFINITY), N64::from_f64(f64::INFINITY));
@@ -281,4 +304,57 @@ mod tests {
     fn r64_infinity() {
         r64(1.0) / r64(0.0);
     }
+
+    #[test]
+    fn hash_consistent_with_eq_for_signed_zero() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of<T: Hash>(value: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(n64(0.0), n64(-0.0));
+        assert_eq!(hash_of(n64(0.0)), hash_of(n64(-0.0)));
+    }
+
+    #[test]
+    fn checked_ops_return_none_on_invalid() {
+        use num_traits::{CheckedAdd, CheckedDiv};
+
+        assert_eq!(R64::new(1.0).checked_div(&R64::new(0.0)), None);
+        assert_eq!(N64::new(1.0).checked_div(&N64::new(0.0)), Some(N64::infinity()));
+        assert_eq!(N64::new(1.0).checked_add(&N64::infinity()), Some(N64::infinity()));
+    }
+
+    #[test]
+    fn euclid_remainder_is_always_nonnegative() {
+        use num_traits::Euclid;
+
+        assert_eq!(r64(7.0).rem_euclid(&r64(3.0)), r64(1.0));
+        assert_eq!(r64(-7.0).rem_euclid(&r64(3.0)), r64(2.0));
+        assert_eq!(r64(-7.0).div_euclid(&r64(3.0)), r64(-3.0));
+    }
+
+    #[test]
+    fn sum_and_product_use_checked_identities_on_empty_iterators() {
+        use num_traits::{Zero, One};
+
+        let empty: Vec<N64> = vec![];
+        assert_eq!(empty.iter().cloned().sum::<N64>(), N64::zero());
+        assert_eq!(empty.iter().cloned().product::<N64>(), N64::one());
+        assert_eq!([n64(1.0), n64(2.0), n64(3.0)].iter().sum::<N64>(), n64(6.0));
+        assert_eq!([n64(1.0), n64(2.0), n64(3.0)].iter().product::<N64>(), n64(6.0));
+    }
+
+    #[test]
+    fn from_primitive_rejects_invalid_values() {
+        use num_traits::FromPrimitive;
+
+        assert_eq!(<R64 as FromPrimitive>::from_f64(f64::INFINITY), None);
+        assert_eq!(<N64 as FromPrimitive>::from_f64(f64::NAN), None);
+        assert_eq!(<R64 as FromPrimitive>::from_i32(42), Some(r64(42.0)));
+    }
 }
@@ -0,0 +1,56 @@
+// Copyright 2016 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::float::FloatCore;
+use serde::{Serialize, Serializer, Deserialize, Deserializer, de};
+use ::{FloatChecker, NoisyFloat};
+
+impl<F: FloatCore + Serialize, C: FloatChecker<F>> Serialize for NoisyFloat<F, C> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, F: FloatCore + Deserialize<'de>, C: FloatChecker<F>> Deserialize<'de> for NoisyFloat<F, C> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = F::deserialize(deserializer)?;
+        Self::try_new(value).ok_or_else(|| de::Error::custom("value is invalid for this NoisyFloat's FloatChecker"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+    use serde_test::{assert_tokens, assert_de_tokens_error, Token};
+
+    #[test]
+    fn round_trip() {
+        assert_tokens(&n64(2.5), &[Token::F64(2.5)]);
+        assert_tokens(&r64(-1.5), &[Token::F64(-1.5)]);
+    }
+
+    #[test]
+    fn deserialize_invalid_value_errors_instead_of_panicking() {
+        assert_de_tokens_error::<N64>(
+            &[Token::F64(::std::f64::NAN)],
+            "value is invalid for this NoisyFloat's FloatChecker",
+        );
+        assert_de_tokens_error::<R64>(
+            &[Token::F64(::std::f64::INFINITY)],
+            "value is invalid for this NoisyFloat's FloatChecker",
+        );
+    }
+}